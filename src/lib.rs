@@ -39,7 +39,10 @@
 )]
 
 use std::collections::HashMap;
-use testcontainers::{core::WaitFor, Container, Image, RunnableImage};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+use testcontainers::{clients::Cli, core::WaitFor, Container, Image, RunnableImage};
 
 /// Available Neo4j plugins.
 /// See [Neo4j operations manual](https://neo4j.com/docs/operations-manual/current/docker/operations/#docker-neo4j-plugins) for more information.
@@ -69,13 +72,73 @@ impl std::fmt::Display for Neo4jLabsPlugin {
     }
 }
 
+/// The Neo4j edition to run.
+///
+/// `Community` is the default. `Enterprise` requires accepting the Neo4j
+/// license agreement, which is handled automatically by [`Neo4j::with_edition`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Neo4jEdition {
+    #[default]
+    Community,
+    Enterprise,
+}
+
+impl std::fmt::Display for Neo4jEdition {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Community => formatter.pad("community"),
+            Self::Enterprise => formatter.pad("enterprise"),
+        }
+    }
+}
+
+impl std::str::FromStr for Neo4jEdition {
+    type Err = String;
+
+    fn from_str(edition: &str) -> Result<Self, Self::Err> {
+        match edition.to_lowercase().as_str() {
+            "community" => Ok(Self::Community),
+            "enterprise" => Ok(Self::Enterprise),
+            other => Err(format!("unknown Neo4j edition: {other}")),
+        }
+    }
+}
+
+/// The readiness strategy used to decide when the Neo4j container can accept connections.
+///
+/// The `testcontainers` version this crate builds on only lets [`Image::ready_conditions`]
+/// wait on container log output, so it has no way to express an HTTP or raw-TCP check
+/// declaratively — it only sees the image's own configuration, not the container's
+/// host-mapped ports. [`WaitStrategy::HttpEndpoint`] and [`WaitStrategy::BoltHandshake`]
+/// are therefore verified by actually connecting to the container after it starts.
+/// Start the container with [`Neo4j::start`] to have this happen automatically, or call
+/// [`Neo4jImage::wait_until_ready`] yourself after `docker.run(...)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WaitStrategy {
+    /// Wait only for the Bolt and startup messages on the container's stdout. This is the
+    /// default, but is brittle across Neo4j versions and log-format changes. Every other
+    /// strategy waits for the same messages too and adds its own check on top of them.
+    #[default]
+    LogMessages,
+    /// Poll the HTTP port (7474) until it answers with a `200`.
+    HttpEndpoint,
+    /// Open a TCP connection to the Bolt port (7687) and complete the Bolt version
+    /// handshake.
+    BoltHandshake,
+}
+
 #[doc = include_str!("../doc/lib.md")]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Neo4j {
     version: String,
     user: String,
     pass: String,
+    edition: Neo4jEdition,
     plugins: Vec<Neo4jLabsPlugin>,
+    config: Vec<(String, String)>,
+    volumes: Vec<(String, String)>,
+    apoc_file_access: bool,
+    wait_strategy: WaitStrategy,
 }
 
 impl Neo4j {
@@ -108,10 +171,120 @@ impl Neo4j {
         self
     }
 
+    /// Define the Neo4j edition to run, e.g. [`Neo4jEdition::Enterprise`].
+    /// Returns new instance.
+    ///
+    /// Choosing [`Neo4jEdition::Enterprise`] accepts the Neo4j license agreement
+    /// on your behalf by setting `NEO4J_ACCEPT_LICENSE_AGREEMENT=yes`. Only do
+    /// this if you are bound by a Neo4j Enterprise license agreement.
+    #[must_use]
+    pub fn with_edition(mut self, edition: Neo4jEdition) -> Self {
+        self.edition = edition;
+        self
+    }
+
+    /// Set a Neo4j configuration setting, given in its `neo4j.conf` dotted form
+    /// (e.g. `dbms.memory.heap.max_size`, `apoc.export.file.enabled`).
+    /// Returns new instance.
+    ///
+    /// Calling this again with the same `key` overrides the previously set value,
+    /// and an explicit call always takes precedence over settings the crate would
+    /// otherwise derive automatically (e.g. the minimum password length).
+    #[must_use]
+    pub fn with_config(mut self, key: &str, value: impl Into<String>) -> Self {
+        let value = value.into();
+        match self.config.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.config.push((key.to_owned(), value)),
+        }
+        self
+    }
+
+    /// Bind-mount a host directory into the container at `container_path`.
+    /// Returns new instance.
+    #[must_use]
+    pub fn with_volume(
+        mut self,
+        host_path: impl Into<String>,
+        container_path: impl Into<String>,
+    ) -> Self {
+        self.volumes.push((host_path.into(), container_path.into()));
+        self
+    }
+
+    /// Bind-mount a host directory as the container's `/import` directory,
+    /// e.g. to make CSV/Cypher files available to `LOAD CSV` or APOC imports.
+    /// Returns new instance.
+    #[must_use]
+    pub fn with_import_dir(self, host_path: impl Into<String>) -> Self {
+        self.with_volume(host_path, "/import")
+    }
+
+    /// Bind-mount a host directory as the container's `/plugins` directory,
+    /// e.g. to supply custom plugin JARs.
+    /// Returns new instance.
+    #[must_use]
+    pub fn with_plugins_dir(self, host_path: impl Into<String>) -> Self {
+        self.with_volume(host_path, "/plugins")
+    }
+
+    /// Bind-mount a host directory as the container's `/data` directory,
+    /// e.g. to persist data across container restarts.
+    /// Returns new instance.
+    #[must_use]
+    pub fn with_data_dir(self, host_path: impl Into<String>) -> Self {
+        self.with_volume(host_path, "/data")
+    }
+
+    /// Enable APOC file import/export by setting `apoc.export.file.enabled`,
+    /// `apoc.import.file.enabled` and `apoc.import.file.use_neo4j_config`.
+    /// Returns new instance.
+    ///
+    /// Requires the [`Neo4jLabsPlugin::Apoc`] or [`Neo4jLabsPlugin::ApocCore`]
+    /// plugin to be enabled via [`Neo4j::with_neo4j_labs_plugin`]; `build()`
+    /// panics otherwise.
+    #[must_use]
+    pub fn with_apoc_file_access(mut self) -> Self {
+        self.apoc_file_access = true;
+        self
+    }
+
+    /// Define the readiness strategy used to decide when the container can accept
+    /// connections, e.g. [`WaitStrategy::HttpEndpoint`] or [`WaitStrategy::BoltHandshake`].
+    /// Returns new instance.
+    ///
+    /// Strategies other than the default [`WaitStrategy::LogMessages`] are only
+    /// enforced if the container is started via [`Neo4j::start`] (or by calling
+    /// [`Neo4jImage::wait_until_ready`] yourself after `docker.run(...)`): the
+    /// `testcontainers` version this crate builds on only lets
+    /// [`Image::ready_conditions`] see the image's own configuration, not the
+    /// container's host-mapped ports, so it cannot perform an HTTP or Bolt socket
+    /// check by itself.
+    #[must_use]
+    pub fn with_wait_strategy(mut self, wait_strategy: WaitStrategy) -> Self {
+        self.wait_strategy = wait_strategy;
+        self
+    }
+
+    /// Start the container via `docker`, then block until it satisfies the configured
+    /// [`WaitStrategy`] beyond the baseline startup log conditions already covered by
+    /// `ready_conditions()`.
+    ///
+    /// Use this instead of `docker.run(RunnableImage::from(neo4j))` whenever a
+    /// non-default [`WaitStrategy`] is set, otherwise the HTTP/Bolt check configured via
+    /// [`Neo4j::with_wait_strategy`] never actually runs.
+    #[must_use]
+    pub fn start(self, docker: &Cli) -> Container<'_, Neo4jImage> {
+        let container = docker.run(RunnableImage::from(self));
+        container.image().wait_until_ready(&container);
+        container
+    }
+
     fn new(user: Option<String>, pass: Option<String>, version: Option<String>) -> Self {
         const USER_VAR: &str = "NEO4J_TEST_USER";
         const PASS_VAR: &str = "NEO4J_TEST_PASS";
         const VERSION_VAR: &str = "NEO4J_VERSION_TAG";
+        const EDITION_VAR: &str = "NEO4J_TEST_EDITION";
 
         const DEFAULT_USER: &str = "neo4j";
         const DEFAULT_PASS: &str = "neo";
@@ -128,12 +301,21 @@ impl Neo4j {
         let version = version
             .or_else(|| var(VERSION_VAR).ok())
             .unwrap_or_else(|| DEFAULT_VERSION_TAG.to_owned());
+        let edition = var(EDITION_VAR)
+            .ok()
+            .and_then(|edition| edition.parse().ok())
+            .unwrap_or_default();
 
         Self {
             version,
             user,
             pass,
+            edition,
             plugins: Vec::new(),
+            config: Vec::new(),
+            volumes: Vec::new(),
+            apoc_file_access: false,
+            wait_strategy: WaitStrategy::default(),
         }
     }
 
@@ -212,16 +394,30 @@ pub struct Neo4jImage {
     version: String,
     user: String,
     pass: String,
+    edition: Neo4jEdition,
     env_vars: HashMap<String, String>,
+    volumes: Vec<(String, String)>,
+    wait_strategy: WaitStrategy,
 }
 
 impl Neo4jImage {
-    fn new(version: String, user: String, pass: String, env_vars: HashMap<String, String>) -> Self {
+    fn new(
+        version: String,
+        user: String,
+        pass: String,
+        edition: Neo4jEdition,
+        env_vars: HashMap<String, String>,
+        volumes: Vec<(String, String)>,
+        wait_strategy: WaitStrategy,
+    ) -> Self {
         Self {
             version,
             user,
             pass,
+            edition,
             env_vars,
+            volumes,
+            wait_strategy,
         }
     }
 
@@ -242,6 +438,109 @@ impl Neo4jImage {
     pub fn pass(&self) -> &str {
         &self.pass
     }
+
+    fn volumes(&self) -> &[(String, String)] {
+        &self.volumes
+    }
+
+    /// Block until the container satisfies its configured [`WaitStrategy`], beyond the
+    /// log-based conditions Docker already waited on in `ready_conditions()`.
+    ///
+    /// For [`WaitStrategy::LogMessages`] this is a no-op. For [`WaitStrategy::HttpEndpoint`]
+    /// and [`WaitStrategy::BoltHandshake`] this opens a real TCP connection to the
+    /// container and polls until the HTTP port answers with a `200`, respectively until
+    /// the Bolt version handshake completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container does not become ready within 60 seconds.
+    pub fn wait_until_ready(&self, container: &Container<'_, Self>) {
+        match self.wait_strategy {
+            WaitStrategy::LogMessages => {}
+            WaitStrategy::HttpEndpoint => Self::wait_for_http_endpoint(container),
+            WaitStrategy::BoltHandshake => Self::wait_for_bolt_handshake(container),
+        }
+    }
+
+    fn wait_for_http_endpoint(container: &Container<'_, Self>) {
+        let port = container
+            .ports()
+            .map_to_host_port_ipv4(7474)
+            .expect("Image exposes 7474 by default");
+
+        Self::poll_until_ready("HTTP endpoint", port, |stream| Self::probe_http(stream, port));
+    }
+
+    fn probe_http(stream: &mut TcpStream, port: u16) -> Option<()> {
+        stream
+            .write_all(Self::http_probe_request(port).as_bytes())
+            .ok()?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).ok()?;
+        response.starts_with("HTTP/1.1 200").then_some(())
+    }
+
+    fn http_probe_request(port: u16) -> String {
+        format!("GET / HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nConnection: close\r\n\r\n")
+    }
+
+    fn wait_for_bolt_handshake(container: &Container<'_, Self>) {
+        let port = container
+            .ports()
+            .map_to_host_port_ipv4(7687)
+            .expect("Image exposes 7687 by default");
+
+        Self::poll_until_ready("Bolt handshake", port, Self::probe_bolt_handshake);
+    }
+
+    fn probe_bolt_handshake(stream: &mut TcpStream) -> Option<()> {
+        stream.write_all(&Self::bolt_handshake_request()).ok()?;
+
+        // The server answers with the 4-byte version it picked, or all zeroes if
+        // none of the proposals is acceptable.
+        let mut chosen_version = [0_u8; 4];
+        stream.read_exact(&mut chosen_version).ok()?;
+        (chosen_version != [0, 0, 0, 0]).then_some(())
+    }
+
+    /// The Bolt handshake: a 4-byte magic preamble followed by four 4-byte version slots.
+    /// Only Bolt 5.0 is proposed, in the first slot; the other three slots are zero.
+    fn bolt_handshake_request() -> [u8; 20] {
+        let mut handshake = [0_u8; 20];
+        handshake[0..4].copy_from_slice(&[0x60, 0x60, 0xB0, 0x17]);
+        handshake[4..8].copy_from_slice(&[0, 0, 0, 5]);
+        handshake
+    }
+
+    fn poll_until_ready(what: &str, port: u16, mut probe: impl FnMut(&mut TcpStream) -> Option<()>) {
+        const TIMEOUT: Duration = Duration::from_secs(60);
+        const IO_TIMEOUT: Duration = Duration::from_secs(5);
+        const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+        let address = SocketAddr::from(([127, 0, 0, 1], port));
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            if let Ok(mut stream) = TcpStream::connect_timeout(&address, IO_TIMEOUT) {
+                stream
+                    .set_read_timeout(Some(IO_TIMEOUT))
+                    .expect("setting a read timeout on a connected stream cannot fail");
+                stream
+                    .set_write_timeout(Some(IO_TIMEOUT))
+                    .expect("setting a write timeout on a connected stream cannot fail");
+
+                if probe(&mut stream).is_some() {
+                    return;
+                }
+            }
+
+            assert!(
+                Instant::now() < deadline,
+                "timed out waiting for Neo4j {what} on port {port} to become ready"
+            );
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
 }
 
 impl Image for Neo4jImage {
@@ -252,10 +551,17 @@ impl Image for Neo4jImage {
     }
 
     fn tag(&self) -> String {
-        self.version.clone()
+        match self.edition {
+            Neo4jEdition::Community => self.version.clone(),
+            Neo4jEdition::Enterprise => format!("{}-enterprise", self.version),
+        }
     }
 
     fn ready_conditions(&self) -> Vec<WaitFor> {
+        // Docker itself always needs a log-based condition to know the process has
+        // started at all; the HTTP/Bolt strategies additionally verify themselves via
+        // `wait_until_ready` once the container is up, since `WaitFor` has no HTTP or
+        // raw-TCP variant to express those checks declaratively.
         vec![
             WaitFor::message_on_stdout("Bolt enabled on"),
             WaitFor::message_on_stdout("Started."),
@@ -295,7 +601,7 @@ impl Neo4j {
     fn conf_env(&self) -> impl IntoIterator<Item = (String, String)> {
         if self.pass.len() < 8 {
             Some((
-                "NEO4J_dbms_security_auth__minimum__password__length".to_owned(),
+                Self::config_env_key("dbms.security.auth_minimum_password_length"),
                 self.pass.len().to_string(),
             ))
         } else {
@@ -303,6 +609,56 @@ impl Neo4j {
         }
     }
 
+    fn config_env(&self) -> impl IntoIterator<Item = (String, String)> + '_ {
+        self.config
+            .iter()
+            .map(|(key, value)| (Self::config_env_key(key), value.clone()))
+    }
+
+    /// Translate a dotted `neo4j.conf` key (e.g. `dbms.memory.heap.max_size`)
+    /// into the environment variable form the Neo4j container image expects.
+    fn config_env_key(key: &str) -> String {
+        format!("NEO4J_{}", key.replace('_', "__").replace('.', "_"))
+    }
+
+    fn license_env(&self) -> impl IntoIterator<Item = (String, String)> {
+        match self.edition {
+            Neo4jEdition::Community => None,
+            Neo4jEdition::Enterprise => Some((
+                "NEO4J_ACCEPT_LICENSE_AGREEMENT".to_owned(),
+                "yes".to_owned(),
+            )),
+        }
+    }
+
+    fn apoc_file_access_env(&self) -> impl IntoIterator<Item = (String, String)> {
+        if !self.apoc_file_access {
+            return Vec::new();
+        }
+
+        assert!(
+            self.plugins.contains(&Neo4jLabsPlugin::Apoc)
+                || self.plugins.contains(&Neo4jLabsPlugin::ApocCore),
+            "with_apoc_file_access() requires the Apoc or ApocCore Neo4j labs plugin, \
+             enable one via with_neo4j_labs_plugin()"
+        );
+
+        vec![
+            (
+                Self::config_env_key("apoc.export.file.enabled"),
+                "true".to_owned(),
+            ),
+            (
+                Self::config_env_key("apoc.import.file.enabled"),
+                "true".to_owned(),
+            ),
+            (
+                Self::config_env_key("apoc.import.file.use_neo4j_config"),
+                "true".to_owned(),
+            ),
+        ]
+    }
+
     fn build(mut self) -> Neo4jImage {
         self.plugins.sort();
         self.plugins.dedup();
@@ -321,7 +677,27 @@ impl Neo4j {
             env_vars.insert(key, value);
         }
 
-        Neo4jImage::new(self.version, self.user, self.pass, env_vars)
+        for (key, value) in self.license_env() {
+            env_vars.insert(key, value);
+        }
+
+        for (key, value) in self.apoc_file_access_env() {
+            env_vars.insert(key, value);
+        }
+
+        for (key, value) in self.config_env() {
+            env_vars.insert(key, value);
+        }
+
+        Neo4jImage::new(
+            self.version,
+            self.user,
+            self.pass,
+            self.edition,
+            env_vars,
+            self.volumes,
+            self.wait_strategy,
+        )
     }
 }
 
@@ -333,13 +709,20 @@ impl From<Neo4j> for Neo4jImage {
 
 impl From<Neo4j> for RunnableImage<Neo4jImage> {
     fn from(neo4j: Neo4j) -> Self {
-        Self::from(neo4j.build())
+        let image = neo4j.build();
+        let volumes = image.volumes().to_vec();
+        volumes
+            .into_iter()
+            .fold(Self::from(image), |runnable_image, volume| {
+                runnable_image.with_volume(volume)
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::{Read, Write};
 
     #[test]
     fn single_plugin_definition() {
@@ -375,4 +758,173 @@ mod tests {
             "[\"apoc\",\"bloom\"]"
         );
     }
+
+    #[test]
+    fn community_edition_by_default() {
+        let neo4j = Neo4j::default().build();
+        assert_eq!(neo4j.tag(), neo4j.version());
+        assert!(!neo4j.env_vars.contains_key("NEO4J_ACCEPT_LICENSE_AGREEMENT"));
+    }
+
+    #[test]
+    fn with_config_maps_dotted_key_to_env_var() {
+        let neo4j = Neo4j::default()
+            .with_config("dbms.memory.heap.max_size", "4G")
+            .build();
+        assert_eq!(
+            neo4j.env_vars.get("NEO4J_dbms_memory_heap_max__size").unwrap(),
+            "4G"
+        );
+    }
+
+    #[test]
+    fn with_config_overrides_previous_call_for_same_key() {
+        let neo4j = Neo4j::default()
+            .with_config("dbms.memory.heap.max_size", "2G")
+            .with_config("dbms.memory.heap.max_size", "4G")
+            .build();
+        assert_eq!(
+            neo4j.env_vars.get("NEO4J_dbms_memory_heap_max__size").unwrap(),
+            "4G"
+        );
+    }
+
+    #[test]
+    fn with_config_overrides_automatic_short_password_default() {
+        let neo4j = Neo4j::from_auth_and_version("5", "neo4j", "short")
+            .with_config("dbms.security.auth_minimum_password_length", "8")
+            .build();
+        assert_eq!(
+            neo4j
+                .env_vars
+                .get("NEO4J_dbms_security_auth__minimum__password__length")
+                .unwrap(),
+            "8"
+        );
+    }
+
+    #[test]
+    fn with_volume_collects_host_and_container_paths() {
+        let neo4j = Neo4j::default()
+            .with_volume("/host/import", "/import")
+            .build();
+        assert_eq!(
+            neo4j.volumes(),
+            &[("/host/import".to_owned(), "/import".to_owned())]
+        );
+    }
+
+    #[test]
+    fn convenience_dir_helpers_map_to_well_known_container_paths() {
+        let neo4j = Neo4j::default()
+            .with_import_dir("/host/import")
+            .with_plugins_dir("/host/plugins")
+            .with_data_dir("/host/data")
+            .build();
+        assert_eq!(
+            neo4j.volumes(),
+            &[
+                ("/host/import".to_owned(), "/import".to_owned()),
+                ("/host/plugins".to_owned(), "/plugins".to_owned()),
+                ("/host/data".to_owned(), "/data".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_apoc_file_access_sets_apoc_env_vars() {
+        let neo4j = Neo4j::default()
+            .with_neo4j_labs_plugin(&[Neo4jLabsPlugin::Apoc])
+            .with_apoc_file_access()
+            .build();
+        assert_eq!(
+            neo4j.env_vars.get("NEO4J_apoc_export_file_enabled").unwrap(),
+            "true"
+        );
+        assert_eq!(
+            neo4j.env_vars.get("NEO4J_apoc_import_file_enabled").unwrap(),
+            "true"
+        );
+        assert_eq!(
+            neo4j
+                .env_vars
+                .get("NEO4J_apoc_import_file_use__neo4j__config")
+                .unwrap(),
+            "true"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "with_apoc_file_access()")]
+    fn with_apoc_file_access_without_plugin_panics() {
+        Neo4j::default().with_apoc_file_access().build();
+    }
+
+    #[test]
+    fn ready_conditions_wait_on_startup_log_messages_regardless_of_wait_strategy() {
+        let neo4j = Neo4j::default()
+            .with_wait_strategy(WaitStrategy::HttpEndpoint)
+            .build();
+        assert_eq!(neo4j.ready_conditions().len(), 2);
+    }
+
+    #[test]
+    fn http_probe_request_targets_root_path_and_host_port() {
+        let request = Neo4jImage::http_probe_request(7474);
+        assert!(request.starts_with("GET / HTTP/1.1\r\n"));
+        assert!(request.contains("Host: 127.0.0.1:7474"));
+    }
+
+    #[test]
+    fn bolt_handshake_request_starts_with_the_bolt_magic_preamble() {
+        let handshake = Neo4jImage::bolt_handshake_request();
+        assert_eq!(&handshake[0..4], &[0x60, 0x60, 0xB0, 0x17]);
+    }
+
+    #[test]
+    fn poll_until_ready_succeeds_once_the_http_probe_sees_a_200() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut request = [0_u8; 1024];
+                let _ = stream.read(&mut request);
+                let _ =
+                    stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            }
+        });
+
+        Neo4jImage::poll_until_ready("HTTP endpoint", port, |stream| {
+            Neo4jImage::probe_http(stream, port)
+        });
+    }
+
+    #[test]
+    fn poll_until_ready_succeeds_once_the_bolt_probe_sees_a_nonzero_version() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut handshake = [0_u8; 20];
+                let _ = stream.read_exact(&mut handshake);
+                let _ = stream.write_all(&[0, 0, 0, 5]);
+            }
+        });
+
+        Neo4jImage::poll_until_ready("Bolt handshake", port, Neo4jImage::probe_bolt_handshake);
+    }
+
+    #[test]
+    fn enterprise_edition_tag_and_license() {
+        let neo4j = Neo4j::from_version("5.6")
+            .with_edition(Neo4jEdition::Enterprise)
+            .build();
+        assert_eq!(neo4j.tag(), "5.6-enterprise");
+        assert_eq!(
+            neo4j.env_vars.get("NEO4J_ACCEPT_LICENSE_AGREEMENT").unwrap(),
+            "yes"
+        );
+    }
 }